@@ -0,0 +1,206 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use self::game_lobby::{GameLobby, GameLobbyRef};
+
+#[ink::contract]
+pub mod game_lobby {
+    use game_public_good::GamePublicGoodRef;
+    use traits::{GameConfigs, GameStatus};
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+    use ink::storage::traits::StorageLayout;
+    use scale::{Decode, Encode};
+
+    /// Lobby errors.
+    #[derive(Encode, Decode, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum LobbyError {
+        /// No room with the given AccountId is tracked by this lobby.
+        RoomNotFound,
+        /// Caller is not the room's creator, only they may close it.
+        NotRoomCreator,
+    }
+
+    /// A cached summary of a room, refreshed on `create_room` and `refresh_room`
+    /// so browsing the lobby doesn't need a cross-contract call per room.
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, Debug)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+    pub struct RoomInfo {
+        pub max_players: u8,
+        pub current_players: u8,
+        pub status: GameStatus,
+        pub closed: bool,
+        /// The account that called `create_room`; the only account allowed to `close_room`.
+        pub creator: AccountId,
+    }
+
+    /// Tracks every `GamePublicGood` room instantiated through this lobby.
+    #[ink(storage)]
+    pub struct GameLobby {
+        /// Every room ever created through this lobby, in creation order.
+        rooms: Vec<AccountId>,
+        /// The cached status summary for each room.
+        room_info: Mapping<AccountId, RoomInfo>,
+    }
+
+    impl GameLobby {
+        /// Constructor that initializes an empty lobby.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                rooms: Vec::new(),
+                room_info: Mapping::default(),
+            }
+        }
+
+        /// Instantiates a new `GamePublicGood` room with the given configs and
+        /// starts tracking it in the lobby.
+        ///
+        /// Returns the AccountId of the newly created room.
+        #[ink(message)]
+        pub fn create_room(&mut self, configs: GameConfigs) -> AccountId {
+            let max_players = configs.max_players;
+            let room: GamePublicGoodRef = GamePublicGoodRef::new(configs).instantiate();
+            let room_id: AccountId = ink::ToAccountId::to_account_id(&room);
+
+            self.rooms.push(room_id);
+            self.room_info.insert(room_id, &RoomInfo {
+                max_players,
+                current_players: 0,
+                status: GameStatus::Initialized,
+                closed: false,
+                creator: self.env().caller(),
+            });
+
+            room_id
+        }
+
+        /// Lists every tracked room that is still open to new players, i.e. not
+        /// closed, still `Initialized`/`Ready`, and with space left to join.
+        #[ink(message)]
+        pub fn list_open_rooms(&self) -> Vec<AccountId> {
+            self.rooms
+                .iter()
+                .filter(|room_id| {
+                    self.room_info.get(*room_id).map_or(false, |info| {
+                        !info.closed
+                            && matches!(info.status, GameStatus::Initialized | GameStatus::Ready)
+                            && info.current_players < info.max_players
+                    })
+                })
+                .cloned()
+                .collect()
+        }
+
+        /// Gets the cached status summary for a room.
+        #[ink(message)]
+        pub fn get_room_info(&self, room_id: AccountId) -> Option<RoomInfo> {
+            self.room_info.get(room_id)
+        }
+
+        /// Marks a room as closed so it no longer shows up in `list_open_rooms`.
+        /// Only the room's creator may close it.
+        #[ink(message)]
+        pub fn close_room(&mut self, room_id: AccountId) -> Result<(), LobbyError> {
+            let mut info = self.room_info.get(room_id).ok_or(LobbyError::RoomNotFound)?;
+            if self.env().caller() != info.creator {
+                return Err(LobbyError::NotRoomCreator)
+            }
+            info.closed = true;
+            self.room_info.insert(room_id, &info);
+            Ok(())
+        }
+
+        /// Re-reads a room's status and player count via a cross-contract call
+        /// and updates the cached `RoomInfo`.
+        #[ink(message)]
+        pub fn refresh_room(&mut self, room_id: AccountId) -> Result<(), LobbyError> {
+            let mut info = self.room_info.get(room_id).ok_or(LobbyError::RoomNotFound)?;
+
+            let room: GamePublicGoodRef = ink::env::call::FromAccountId::from_account_id(room_id);
+            info.status = room.get_status();
+            info.current_players = room.get_players().len() as u8;
+
+            self.room_info.insert(room_id, &info);
+            Ok(())
+        }
+    }
+
+    /// Unit tests.
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// A new lobby starts out with no rooms.
+        #[ink::test]
+        fn new_lobby_has_no_rooms() {
+            let lobby = GameLobby::new();
+            assert_eq!(lobby.list_open_rooms(), vec![]);
+        }
+
+        /// Looking up an untracked room returns `None`.
+        #[ink::test]
+        fn unknown_room_has_no_info() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let lobby = GameLobby::new();
+            assert_eq!(lobby.get_room_info(accounts.alice), None);
+        }
+
+        /// Closing an untracked room fails.
+        #[ink::test]
+        fn cannot_close_unknown_room() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut lobby = GameLobby::new();
+            assert_eq!(lobby.close_room(accounts.alice).err(), Some(LobbyError::RoomNotFound));
+        }
+    }
+
+    /// On-chain (E2E) tests.
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::build_message;
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        /// A room created through the lobby shows up as open.
+        #[ink_e2e::test]
+        async fn created_room_is_listed_as_open(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = GameLobbyRef::new();
+            let contract_account_id = client
+                .instantiate("game_lobby", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiation failed")
+                .account_id;
+
+            let configs = GameConfigs {
+                max_players: 10,
+                min_players: 2,
+                min_round_contribution: None,
+                max_round_contribution: Some(1_000),
+                post_round_actions: false,
+                round_timeout: None,
+                max_rounds: None,
+                join_fee: None,
+                is_rounds_based: false,
+                contribution_multiplier: 15_000,
+                blind_contributions: false,
+            };
+
+            let create_room = build_message::<GameLobbyRef>(contract_account_id.clone())
+                .call(|lobby| lobby.create_room(configs.clone()));
+            client
+                .call(&ink_e2e::alice(), create_room, 0, None)
+                .await
+                .expect("create_room failed");
+
+            let list_open_rooms = build_message::<GameLobbyRef>(contract_account_id.clone())
+                .call(|lobby| lobby.list_open_rooms());
+            let open_rooms = client
+                .call_dry_run(&ink_e2e::alice(), &list_open_rooms, 0, None)
+                .await;
+            assert_eq!(open_rooms.return_value().len(), 1);
+
+            Ok(())
+        }
+    }
+}
@@ -4,9 +4,92 @@ pub use self::game_public_good::{GamePublicGood, GamePublicGoodRef};
 
 #[ink::contract]
 pub mod game_public_good {
-    use traits::{ GameLifecycle, GameRound, GameStatus, GameConfigs, GameError, RoundStatus };
+    use traits::{ GameLifecycle, GameRound, GameStatus, GameConfigs, GameError, RoundStatus, PlayerStats, BPS };
     use ink::prelude::vec::Vec;
     use ink::env::hash::{Blake2x256, HashOutput};
+    use ink::storage::Mapping;
+
+    /// Emitted once, right after the game instance is constructed.
+    #[ink(event)]
+    pub struct GameCreated {
+        #[ink(topic)]
+        game_id: AccountId,
+        max_players: u8,
+        min_players: u8,
+    }
+
+    /// Emitted every time a new player joins the game.
+    #[ink(event)]
+    pub struct PlayerJoined {
+        #[ink(topic)]
+        game_id: AccountId,
+        #[ink(topic)]
+        player: AccountId,
+        /// The number of players that have joined so far, including this one.
+        players_count: u8,
+    }
+
+    /// Emitted once the game transitions from `Initialized` to `Started`.
+    #[ink(event)]
+    pub struct GameStarted {
+        #[ink(topic)]
+        game_id: AccountId,
+        round_id: u8,
+        players_count: u8,
+    }
+
+    /// Emitted every time a player commits to the current round.
+    #[ink(event)]
+    pub struct PlayerCommitted {
+        #[ink(topic)]
+        game_id: AccountId,
+        #[ink(topic)]
+        player: AccountId,
+        round_id: u8,
+        /// The number of commitments recorded for this round so far, including this one.
+        commits_count: u8,
+    }
+
+    /// Emitted every time a player reveals their commitment for the current round.
+    #[ink(event)]
+    pub struct PlayerRevealed {
+        #[ink(topic)]
+        game_id: AccountId,
+        #[ink(topic)]
+        player: AccountId,
+        round_id: u8,
+        /// The number of reveals recorded for this round so far, including this one.
+        reveals_count: u8,
+    }
+
+    /// Emitted once a round has been paid out via `complete_round`.
+    #[ink(event)]
+    pub struct RoundCompleted {
+        #[ink(topic)]
+        game_id: AccountId,
+        round_id: u8,
+        total_contribution: Balance,
+        total_reward: Balance,
+    }
+
+    /// Emitted once a stale round has been paid out via `force_complete_round`.
+    #[ink(event)]
+    pub struct RoundForceCompleted {
+        #[ink(topic)]
+        game_id: AccountId,
+        round_id: u8,
+        /// The number of players who forfeited their stake for failing to play.
+        forfeited_players: u8,
+    }
+
+    /// Emitted once the game has been closed, either explicitly via `end_game`
+    /// or implicitly once a round completes with `max_rounds` reached.
+    #[ink(event)]
+    pub struct GameEnded {
+        #[ink(topic)]
+        game_id: AccountId,
+        rounds_played: u8,
+    }
 
     /// A single game storage.
     /// Each contract (along with its storage) represents a single game instance.
@@ -24,20 +107,49 @@ pub mod game_public_good {
         next_round_id: u8,
         /// The configurations of the game
         configs: GameConfigs,
+        /// Rewards owed to a player from completed rounds, withdrawable via `claim_rewards`.
+        claimable_balances: Mapping<AccountId, Balance>,
+        /// Tracks whether a player's one-time join fee has already been forfeited into
+        /// the pot by `force_complete_round`, so a repeat offender isn't charged twice.
+        join_fee_forfeited: Mapping<AccountId, bool>,
+        /// Cross-round performance for each player, backing `get_leaderboard`.
+        player_stats: Mapping<AccountId, PlayerStats>,
     }
 
     impl GamePublicGood {
         /// Constructor that initializes the GamePublicGood struct
         #[ink(constructor)]
         pub fn new(configs: GameConfigs) -> Self {
-            Self {
+            // the pot multiplier must keep contributing individually irrational
+            // (r < n) while still making the pot grow (r > 1), or the public-goods
+            // tension this game is meant to model doesn't exist. a round can start
+            // with as few as `min_players`, so the invariant must hold against that
+            // floor, not `max_players`, or a round can start with r > n.
+            assert!(
+                configs.contribution_multiplier > BPS
+                    && (configs.contribution_multiplier as u64) < configs.min_players as u64 * BPS as u64,
+                "contribution_multiplier must satisfy 1 < r < min_players"
+            );
+
+            let instance = Self {
                 players: Vec::new(),
                 status: GameStatus::Initialized,
                 rounds: Vec::new(),
                 current_round: None,
                 next_round_id: 1,
                 configs,
-            }
+                claimable_balances: Mapping::default(),
+                join_fee_forfeited: Mapping::default(),
+                player_stats: Mapping::default(),
+            };
+
+            instance.env().emit_event(GameCreated {
+                game_id: instance.env().account_id(),
+                max_players: instance.configs.max_players,
+                min_players: instance.configs.min_players,
+            });
+
+            instance
         }
 
         /// A default constructor that initializes this game with 10 players.
@@ -53,8 +165,85 @@ pub mod game_public_good {
                 max_rounds: None,
                 join_fee: None,
                 is_rounds_based: false,
+                contribution_multiplier: 15_000,
+                blind_contributions: false,
             })
         }
+
+        /// Withdraws the full reward balance accrued for the caller across completed rounds.
+        ///
+        /// Returns the amount transferred.
+        #[ink(message)]
+        pub fn claim_rewards(&mut self) -> Result<Balance, GameError> {
+            let caller = self.env().caller();
+            let balance = self.claimable_balances.get(caller).unwrap_or(0);
+
+            if balance == 0 {
+                return Ok(0)
+            }
+
+            self.claimable_balances.remove(caller);
+            self.env().transfer(caller, balance).map_err(|_| GameError::TransferFailed)?;
+
+            Ok(balance)
+        }
+
+        /// Returns every player's cross-round stats, ranked by net gain
+        /// (`total_rewarded` minus `total_contributed`) from biggest to smallest.
+        #[ink(message)]
+        pub fn get_leaderboard(&self) -> Vec<(AccountId, PlayerStats)> {
+            let mut leaderboard: Vec<(AccountId, PlayerStats)> = self.players
+                .iter()
+                .map(|player| (*player, self.player_stats.get(player).unwrap_or_default()))
+                .collect();
+
+            leaderboard.sort_by(|(_, a), (_, b)| {
+                let net_a = a.total_rewarded as i128 - a.total_contributed as i128;
+                let net_b = b.total_rewarded as i128 - b.total_contributed as i128;
+                net_b.cmp(&net_a)
+            });
+
+            leaderboard
+        }
+
+        /// Folds one round's outcome for `player` into their cross-round `PlayerStats`.
+        fn record_stats(&mut self, player: AccountId, contributed: u128, rewarded: u128) {
+            let mut stats = self.player_stats.get(player).unwrap_or_default();
+            stats.rounds_played += 1;
+            stats.total_contributed += contributed;
+            stats.total_rewarded += rewarded;
+            if contributed == 0 {
+                stats.defections += 1;
+            }
+            self.player_stats.insert(player, &stats);
+        }
+
+        /// Builds the next `Ready` round, stamping its start block and bumping `next_round_id`.
+        fn new_round(&mut self) -> GameRound {
+            let round = GameRound {
+                id: self.next_round_id,
+                status: RoundStatus::Ready,
+                player_commits: Vec::new(),
+                player_reveals: Vec::new(),
+                player_contributions: Vec::new(),
+                total_contribution: 0,
+                total_reward: 0,
+                started_at: self.env().block_number(),
+            };
+            self.next_round_id += 1;
+            round
+        }
+
+        /// Marks the game as `Ended` and emits [`GameEnded`], shared by `end_game` and
+        /// the max-rounds-reached branch of `complete_round`/`force_complete_round` so
+        /// the event fires no matter which path closes the game out.
+        fn end_current_game(&mut self) {
+            self.status = GameStatus::Ended;
+            self.env().emit_event(GameEnded {
+                game_id: self.env().account_id(),
+                rounds_played: self.rounds.len() as u8,
+            });
+        }
     }
 
     /// An implementation of the `GameLifecycle` trait for the `GamePublicGood` contract.
@@ -96,7 +285,15 @@ pub mod game_public_good {
             }
 
             self.players.push(player);
-            Ok(self.players.len() as u8)
+            let players_count = self.players.len() as u8;
+
+            self.env().emit_event(PlayerJoined {
+                game_id: self.env().account_id(),
+                player,
+                players_count,
+            });
+
+            Ok(players_count)
         }
 
         #[ink(message, payable)]
@@ -111,17 +308,17 @@ pub mod game_public_good {
                 _ => (),
             }
 
-            self.current_round = Some(GameRound {
-                id: self.next_round_id,
-                status: RoundStatus::Ready,
-                player_commits: Vec::new(),
-                player_reveals: Vec::new(),
-                player_contributions: Vec::new(),
-                total_contribution: 0,
-                total_reward: 0,
-            });
+            let round = self.new_round();
+            let round_id = round.id;
+            self.current_round = Some(round);
             self.status = GameStatus::Started;
-            self.next_round_id += 1;
+
+            self.env().emit_event(GameStarted {
+                game_id: self.env().account_id(),
+                round_id,
+                players_count: self.players.len() as u8,
+            });
+
             Ok(())
         }
 
@@ -134,12 +331,13 @@ pub mod game_public_good {
                 (_, true, _) => {
                     return Err(GameError::NoCurrentRound)
                 },
-                (_, _, value) if Some(value) < self.configs.max_round_contribution => {
-                    // NOTE: the issue here is since this game is publicgood, some amount has to be
-                    // contributed to the pot. So, we need to check if the player has contributed
-                    // that amount. But we also don't want to reveal the contribution :)
-                    // one way is to have the payable amount always be fixed and be maxed out
-                    // while the hashed commitment contains the real amount to be contributed.
+                // blind mode: the payable amount is always the fixed collateral (maxed
+                // out), so it can't leak the hidden contribution encoded inside
+                // `commitment` the way a variable payable amount would.
+                (_, _, value) if self.configs.blind_contributions && Some(value) != self.configs.max_round_contribution => {
+                    return Err(GameError::InvalidRoundContribution)
+                },
+                (_, _, value) if !self.configs.blind_contributions && Some(value) < self.configs.max_round_contribution => {
                     return Err(GameError::InvalidRoundContribution)
                 },
                 _ => ()
@@ -163,12 +361,18 @@ pub mod game_public_good {
 
             current_round.total_contribution += value;
 
-            // check if all players have committed
-            if current_round.player_commits.len() == self.players.len() {
-                // TODO: emit AllPlayersCommitted event
-            }
+            let round_id = current_round.id;
+            let commits_count = current_round.player_commits.len() as u8;
 
             self.current_round = Some(current_round.clone());
+
+            self.env().emit_event(PlayerCommitted {
+                game_id: self.env().account_id(),
+                player: caller,
+                round_id,
+                commits_count,
+            });
+
             Ok(())
         }
 
@@ -196,30 +400,261 @@ pub mod game_public_good {
                 None => return Err(GameError::CommitmentNotFound),
             }
 
+            // the revealed amount can never exceed what this player actually escrowed
+            // in `play_round` (the real payment in non-blind mode, or the fixed
+            // collateral in blind mode) — otherwise they could pay in the minimum
+            // but reveal an inflated amount, folding funds the contract never
+            // received into `total_contribution`.
+            let player_contribution = self.current_round
+                .as_ref()
+                .unwrap()
+                .player_contributions
+                .iter()
+                .find(|(player, _)| player == &caller)
+                .map(|(_, value)| *value)
+                .unwrap_or(0);
+            if reveal.0 > player_contribution {
+                return Err(GameError::InvalidRoundContribution)
+            }
+
+            // a player can only reveal once, otherwise they could pad their own
+            // contribution into the total multiple times and drain the pot
+            if self.current_round.as_ref().unwrap().player_reveals.iter().any(|(player, _)| player == &caller) {
+                return Err(GameError::AlreadyRevealed)
+            }
+
             // store the reveal
-            self.current_round.as_mut().unwrap().player_reveals.push((
+            let current_round = self.current_round.as_mut().unwrap();
+            current_round.player_reveals.push((
                 caller,
                 reveal,
             ));
-
-            // TODO: emit an event for the reveal
+            let round_id = current_round.id;
+            let reveals_count = current_round.player_reveals.len() as u8;
+
+            self.env().emit_event(PlayerRevealed {
+                game_id: self.env().account_id(),
+                player: caller,
+                round_id,
+                reveals_count,
+            });
 
             Ok(())
         }
 
         #[ink(message, payable)]
         fn complete_round(&mut self) -> Result<(), GameError> {
-            todo!("implement")
+            if self.status != GameStatus::Started {
+                return Err(GameError::GameNotStarted)
+            }
+
+            let mut current_round = self.current_round.clone().ok_or(GameError::NoCurrentRound)?;
+
+            if current_round.status != RoundStatus::Ready && current_round.status != RoundStatus::Started {
+                return Err(GameError::RoundAlreadyCompleted)
+            }
+
+            if current_round.player_reveals.len() != self.players.len() {
+                return Err(GameError::IncompleteReveals)
+            }
+
+            // only revealed contributions count towards the pot; a player who
+            // committed but never revealed forfeits their stake (see `force_complete_round`)
+            let total_contribution: u128 = current_round.player_reveals
+                .iter()
+                .map(|(_, (amount, _))| amount)
+                .sum();
+            let total_reward = total_contribution * self.configs.contribution_multiplier as u128 / BPS as u128;
+            let reward_share = total_reward / self.players.len() as u128;
+
+            for player in self.players.iter() {
+                let balance = self.claimable_balances.get(player).unwrap_or(0);
+                self.claimable_balances.insert(player, &(balance + reward_share));
+            }
+
+            // blind mode: everyone paid in the full collateral up front, so whatever
+            // they didn't actually contribute is owed back to them now that it's safe
+            // to reveal (the round is over and the pot is settled).
+            if self.configs.blind_contributions {
+                for (player, (amount, _)) in current_round.player_reveals.iter() {
+                    let collateral = current_round.player_contributions
+                        .iter()
+                        .find(|(contributor, _)| contributor == player)
+                        .map(|(_, collateral)| *collateral)
+                        .unwrap_or(0);
+                    let refund = collateral.saturating_sub(*amount);
+                    let balance = self.claimable_balances.get(player).unwrap_or(0);
+                    self.claimable_balances.insert(player, &(balance + refund));
+                }
+            }
+
+            for (player, (amount, _)) in current_round.player_reveals.iter() {
+                self.record_stats(*player, *amount, reward_share);
+            }
+
+            current_round.total_contribution = total_contribution;
+            current_round.total_reward = total_reward;
+            current_round.status = RoundStatus::PendingRewardsClaim;
+            let round_id = current_round.id;
+
+            self.rounds.push(current_round);
+            self.current_round = None;
+
+            self.env().emit_event(RoundCompleted {
+                game_id: self.env().account_id(),
+                round_id,
+                total_contribution,
+                total_reward,
+            });
+
+            match self.configs.max_rounds {
+                Some(max_rounds) if self.rounds.len() as u32 >= max_rounds => {
+                    self.end_current_game();
+                }
+                _ => {
+                    self.current_round = Some(self.new_round());
+                }
+            }
+
+            Ok(())
         }
 
         #[ink(message, payable)]
         fn force_complete_round(&mut self) -> Result<(), GameError> {
-            todo!("implement")
+            if self.status != GameStatus::Started {
+                return Err(GameError::GameNotStarted)
+            }
+
+            let caller = self.env().caller();
+            let mut current_round = self.current_round.clone().ok_or(GameError::NoCurrentRound)?;
+
+            if current_round.status != RoundStatus::Ready && current_round.status != RoundStatus::Started {
+                return Err(GameError::RoundAlreadyCompleted)
+            }
+
+            // only a player who has already committed may force a stalled round closed
+            if !current_round.player_commits.iter().any(|(player, _)| player == &caller) {
+                return Err(GameError::CommitmentNotFound)
+            }
+
+            let timeout = self.configs.round_timeout.unwrap_or(20);
+            if self.env().block_number() < current_round.started_at + timeout {
+                return Err(GameError::RoundNotExpired)
+            }
+
+            // honest players committed *and* revealed; everyone else forfeits their stake
+            let honest_players: Vec<AccountId> = current_round.player_reveals
+                .iter()
+                .map(|(player, _)| *player)
+                .collect();
+            let forfeiting_players: Vec<AccountId> = self.players
+                .iter()
+                .filter(|player| !honest_players.contains(player))
+                .cloned()
+                .collect();
+
+            let revealed_contribution: u128 = current_round.player_reveals
+                .iter()
+                .map(|(_, (amount, _))| amount)
+                .sum();
+            // the join fee is paid once at `join`, so only fold it into the pot the
+            // first time a given player is caught defecting, not on every stale round
+            let newly_forfeiting_players: Vec<AccountId> = forfeiting_players
+                .iter()
+                .filter(|player| !self.join_fee_forfeited.get(player).unwrap_or(false))
+                .cloned()
+                .collect();
+            for player in newly_forfeiting_players.iter() {
+                self.join_fee_forfeited.insert(player, &true);
+            }
+
+            let forfeited_stake: u128 = current_round.player_contributions
+                .iter()
+                .filter(|(player, _)| forfeiting_players.contains(player))
+                .map(|(_, value)| value)
+                .sum::<u128>()
+                + newly_forfeiting_players.len() as u128 * self.configs.join_fee.unwrap_or(0);
+
+            let total_contribution = revealed_contribution + forfeited_stake;
+            let total_reward = total_contribution * self.configs.contribution_multiplier as u128 / BPS as u128;
+
+            let reward_share = if honest_players.is_empty() {
+                0
+            } else {
+                total_reward / honest_players.len() as u128
+            };
+            for player in honest_players.iter() {
+                let balance = self.claimable_balances.get(player).unwrap_or(0);
+                self.claimable_balances.insert(player, &(balance + reward_share));
+            }
+
+            // blind mode: honest revealers paid the full collateral up front, so
+            // whatever they didn't actually contribute is owed back to them now
+            // (forfeiting players never revealed, so their collateral is the penalty).
+            if self.configs.blind_contributions {
+                for (player, (amount, _)) in current_round.player_reveals.iter() {
+                    let collateral = current_round.player_contributions
+                        .iter()
+                        .find(|(contributor, _)| contributor == player)
+                        .map(|(_, collateral)| *collateral)
+                        .unwrap_or(0);
+                    let refund = collateral.saturating_sub(*amount);
+                    let balance = self.claimable_balances.get(player).unwrap_or(0);
+                    self.claimable_balances.insert(player, &(balance + refund));
+                }
+            }
+
+            for (player, (amount, _)) in current_round.player_reveals.iter() {
+                self.record_stats(*player, *amount, reward_share);
+            }
+            for player in forfeiting_players.iter() {
+                self.record_stats(*player, 0, 0);
+            }
+
+            current_round.total_contribution = total_contribution;
+            current_round.total_reward = total_reward;
+            current_round.status = RoundStatus::PendingRewardsClaim;
+            let round_id = current_round.id;
+
+            self.rounds.push(current_round);
+            self.current_round = None;
+
+            self.env().emit_event(RoundForceCompleted {
+                game_id: self.env().account_id(),
+                round_id,
+                forfeited_players: forfeiting_players.len() as u8,
+            });
+
+            match self.configs.max_rounds {
+                Some(max_rounds) if self.rounds.len() as u32 >= max_rounds => {
+                    self.end_current_game();
+                }
+                _ => {
+                    self.current_round = Some(self.new_round());
+                }
+            }
+
+            Ok(())
         }
 
         #[ink(message, payable)]
         fn end_game(&mut self) -> Result<(), GameError> {
-            todo!("implement")
+            if self.status != GameStatus::Started {
+                return Err(GameError::GameNotStarted)
+            }
+
+            if let Some(round) = &self.current_round {
+                // a round nobody has committed to yet has nothing left to settle
+                if !round.player_commits.is_empty()
+                    && round.status != RoundStatus::PendingRewardsClaim
+                    && round.status != RoundStatus::Ended {
+                    return Err(GameError::FailedToCloseRound)
+                }
+            }
+
+            self.end_current_game();
+
+            Ok(())
         }
     }
 
@@ -249,6 +684,8 @@ pub mod game_public_good {
                 max_rounds: None,
                 join_fee: None,
                 is_rounds_based: false,
+                contribution_multiplier: 15_000,
+                blind_contributions: false,
             });
             assert_eq!(game_public_good.players, vec![]);
             assert_eq!(game_public_good.get_current_round(), None);
@@ -334,6 +771,165 @@ pub mod game_public_good {
             // cannot start, not enough players
             assert_eq!(game_public_good.start_game().err(), Some(GameError::NotEnoughPlayers));
         }
+
+        /// Hashes `(amount, nonce)` the same way `reveal_round` does, for building commitments.
+        fn commitment_for(amount: u128, nonce: u128) -> Hash {
+            let data = [amount.to_le_bytes(), nonce.to_le_bytes()].concat();
+            let mut output = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Blake2x256>(&data, &mut output);
+            output.into()
+        }
+
+        /// A round where every player reveals splits the pot evenly across all players.
+        #[ink::test]
+        fn full_round_pays_out_equal_shares() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut game_public_good = GamePublicGood::default();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(game_public_good.join(accounts.alice).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(game_public_good.join(accounts.bob).is_ok());
+            assert!(game_public_good.start_game().is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            assert!(game_public_good.play_round(commitment_for(1_000, 1)).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            assert!(game_public_good.play_round(commitment_for(1_000, 2)).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(game_public_good.reveal_round((1_000, 1)).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(game_public_good.reveal_round((1_000, 2)).is_ok());
+
+            assert!(game_public_good.complete_round().is_ok());
+
+            // total_contribution = 2_000, total_reward = 2_000 * 1.5 = 3_000, split 2 ways
+            assert_eq!(game_public_good.claimable_balances.get(accounts.alice), Some(1_500));
+            assert_eq!(game_public_good.claimable_balances.get(accounts.bob), Some(1_500));
+        }
+
+        /// A player who commits but never reveals forfeits their stake once the round
+        /// times out, and the honest revealer claims the whole pot.
+        #[ink::test]
+        fn forced_round_penalizes_the_defector() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut game_public_good = GamePublicGood::default();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(game_public_good.join(accounts.alice).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(game_public_good.join(accounts.bob).is_ok());
+            ink::env::test::set_block_number::<ink::env::DefaultEnvironment>(0);
+            assert!(game_public_good.start_game().is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            assert!(game_public_good.play_round(commitment_for(1_000, 1)).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            assert!(game_public_good.play_round(commitment_for(1_000, 2)).is_ok());
+
+            // alice reveals honestly, bob never does
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(game_public_good.reveal_round((1_000, 1)).is_ok());
+
+            // the round has not timed out yet (default timeout is 20 blocks)
+            assert_eq!(game_public_good.force_complete_round().err(), Some(GameError::RoundNotExpired));
+
+            ink::env::test::set_block_number::<ink::env::DefaultEnvironment>(20);
+            assert!(game_public_good.force_complete_round().is_ok());
+
+            // total_contribution = alice's 1_000 + bob's forfeited 1_000 = 2_000,
+            // total_reward = 2_000 * 1.5 = 3_000, paid out solely to alice
+            assert_eq!(game_public_good.claimable_balances.get(accounts.alice), Some(3_000));
+            assert_eq!(game_public_good.claimable_balances.get(accounts.bob), None);
+            assert_eq!(game_public_good.player_stats.get(accounts.bob).unwrap().defections, 1);
+        }
+
+        /// The leaderboard ranks players by net gain, biggest first.
+        #[ink::test]
+        fn leaderboard_orders_by_net_gain() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut game_public_good = GamePublicGood::default();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(game_public_good.join(accounts.alice).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(game_public_good.join(accounts.bob).is_ok());
+            assert!(game_public_good.start_game().is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            assert!(game_public_good.play_round(commitment_for(1_000, 1)).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            assert!(game_public_good.play_round(commitment_for(0, 2)).is_ok());
+
+            // alice contributes honestly, bob free-rides by revealing a contribution of 0
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(game_public_good.reveal_round((1_000, 1)).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(game_public_good.reveal_round((0, 2)).is_ok());
+
+            assert!(game_public_good.complete_round().is_ok());
+
+            // total_contribution = 1_000, total_reward = 1_500, split evenly: 750 each.
+            // bob nets +750, alice nets -250, so bob ranks first.
+            let leaderboard = game_public_good.get_leaderboard();
+            assert_eq!(leaderboard[0].0, accounts.bob);
+            assert_eq!(leaderboard[1].0, accounts.alice);
+        }
+
+        /// In blind mode every player escrows the fixed collateral up front; once the
+        /// round is revealed, `complete_round` pays out the reward share and refunds
+        /// each player whatever collateral they didn't actually contribute.
+        #[ink::test]
+        fn blind_round_refunds_unspent_collateral() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut game_public_good = GamePublicGood::new(GameConfigs {
+                max_players: 10,
+                min_players: 2,
+                min_round_contribution: None,
+                max_round_contribution: Some(1_000),
+                post_round_actions: false,
+                round_timeout: None,
+                max_rounds: None,
+                join_fee: None,
+                is_rounds_based: false,
+                contribution_multiplier: 15_000,
+                blind_contributions: true,
+            });
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(game_public_good.join(accounts.alice).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(game_public_good.join(accounts.bob).is_ok());
+            assert!(game_public_good.start_game().is_ok());
+
+            // both players escrow the full collateral, regardless of what they'll reveal
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            assert!(game_public_good.play_round(commitment_for(400, 1)).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            assert!(game_public_good.play_round(commitment_for(600, 2)).is_ok());
+
+            // alice reveals she only meant to contribute 400 of her 1_000 collateral
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(game_public_good.reveal_round((400, 1)).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(game_public_good.reveal_round((600, 2)).is_ok());
+
+            assert!(game_public_good.complete_round().is_ok());
+
+            // total_contribution = 1_000, total_reward = 1_500, split evenly: 750 each.
+            // alice is refunded 1_000 - 400 = 600, bob is refunded 1_000 - 600 = 400.
+            assert_eq!(game_public_good.claimable_balances.get(accounts.alice), Some(750 + 600));
+            assert_eq!(game_public_good.claimable_balances.get(accounts.bob), Some(750 + 400));
+        }
     }
 
     /// On-chain (E2E) tests.
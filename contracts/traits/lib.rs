@@ -2,11 +2,12 @@
 
 use ink::primitives::{AccountId, Hash};
 use ink::prelude::vec::Vec;
-use ink::primitives::AccountId;
 use ink::storage::traits::StorageLayout;
 use scale::{Decode, Encode};
 
-// TODO: add some events
+/// Denominator used to express `GameConfigs::contribution_multiplier` as a fixed-point ratio,
+/// e.g. a multiplier of `1.5x` is encoded as `15_000`.
+pub const BPS: u32 = 10_000;
 
 /// Game errors.
 #[derive(Encode, Decode, Debug, PartialEq, Eq)]
@@ -18,12 +19,32 @@ pub enum GameError {
     MaxPlayersReached,
     /// Fees paid to join the game are not sufficient
     InsufficientJoiningFees,
+    /// The game is not in a state that allows it to be started
+    InvalidGameStartState,
+    /// Not enough players have joined to start the game
+    NotEnoughPlayers,
+    /// The game has not been started yet
+    GameNotStarted,
+    /// There is no round currently in progress
+    NoCurrentRound,
+    /// The contribution paid in does not match what the round requires
+    InvalidRoundContribution,
+    /// The revealed data does not hash to the stored commitment
+    InvalidReveal,
+    /// This player has already revealed their commitment for the current round
+    AlreadyRevealed,
     /// The round has not expired yet
     RoundNotExpired,
     /// No commitment made by player for the current round
     CommitmentNotFound,
     /// Round cannot be closed
     FailedToCloseRound,
+    /// The round has already been completed, it cannot be completed again
+    RoundAlreadyCompleted,
+    /// Not every player has revealed their commitment yet
+    IncompleteReveals,
+    /// The runtime refused to transfer funds out of the contract
+    TransferFailed,
 }
 
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, Debug)]
@@ -47,13 +68,15 @@ pub enum RoundStatus {
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
 pub struct GameRound {
-    pub round_number: u32,
+    pub id: u8,
     pub status: RoundStatus,
-    pub player_commits: Vec<(AccountId, u128)>,
-    pub player_reveals: Vec<(AccountId, u128)>,
+    pub player_commits: Vec<(AccountId, Hash)>,
+    pub player_reveals: Vec<(AccountId, (u128, u128))>,
     pub player_contributions: Vec<(AccountId, u128)>,
     pub total_contribution: u128,
     pub total_reward: u128,
+    /// The block this round became active, used to detect a stale round in `force_complete_round`.
+    pub started_at: u32,
 }
 
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug)]
@@ -68,12 +91,34 @@ pub struct GameConfigs {
     pub round_timeout: Option<u32>,
     pub max_rounds: Option<u32>,
     pub join_fee: Option<u128>,
+    pub is_rounds_based: bool,
+    /// The pot multiplier `r`, expressed in [`BPS`] (e.g. `15_000` is `1.5x`).
+    /// Must satisfy `1 < r < n` (where `n` is `min_players`, the smallest a round
+    /// can ever be), otherwise contributing is never individually rational and
+    /// the public-goods tension disappears.
+    pub contribution_multiplier: u32,
+    /// When `true`, `play_round` requires a fixed collateral (`max_round_contribution`)
+    /// instead of the real contribution, which is only revealed via the commitment.
+    /// Unspent collateral is refunded once the round is settled in `complete_round`.
+    pub blind_contributions: bool,
+}
+
+/// A player's accumulated performance across every round of a game instance.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct PlayerStats {
+    pub rounds_played: u32,
+    pub total_contributed: u128,
+    pub total_rewarded: u128,
+    /// The number of rounds in which the player withheld their contribution
+    /// (revealed `0`, or never revealed/committed and had their stake forfeited).
+    pub defections: u32,
 }
 
 /// Defines the basic game lifecycle methods.
 #[ink::trait_definition]
 pub trait GameLifecycle {
-    /// Gets the AccountId of each each player within this instance of the game.
+    /// Gets the configs this game instance was created with.
     #[ink(message)]
     fn get_configs(&self) -> GameConfigs;
 
@@ -100,32 +145,37 @@ pub trait GameLifecycle {
     /// only works once, fails on subsequent calls (since the state has changed)
     /// emits a relevant event (all events should include some game ID for the UIs that are listening)
     #[ink(message, payable)]
-    fn startGame(&mut self) -> Result<(), GameError>;
+    fn start_game(&mut self) -> Result<(), GameError>;
 
     /// Makes a commitment to the current round by the player who called the method
     /// The payed amount is the round contribution, to be validated based on configs
     /// Must be recorded in the GameRound storage
     /// emits a relevant event (should include the total # of commitments in the round, helps UI know if everyone played)
     #[ink(message, payable)]
-    fn playRound(&mut self, commitment: Hash) -> Result<(), GameError>;
+    fn play_round(&mut self, commitment: Hash) -> Result<(), GameError>;
 
     /// receives data which if hashed must match the commitment for the round made earlier
     /// throws an error if the round has no commitment for the caller
     /// prepares the next round if max rounds not reached
     /// emits a relevant event
     #[ink(message, payable)]
-    fn revealRound(&mut self, reveal: ([u8; 32], u8)) -> Result<(), GameError>;
+    fn reveal_round(&mut self, reveal: (u128, u128)) -> Result<(), GameError>;
 
     /// claims rewards of the round (if applicable and all players have revealed)
     /// prepares the next round
     /// emits a relevant event
     #[ink(message, payable)]
-    fn completeRound(&mut self) -> Result<(), GameError>;
+    fn complete_round(&mut self) -> Result<(), GameError>;
 
     /// succeeds only if the caller has already made a commitment
     /// succeeds only if the round expired (passed the block timeout in config // should default to 10 or 20 blocks if None)
     /// a penalty is incurred by the players who did not play (joining fee is not returned)
     /// emits a relevant event
     #[ink(message, payable)]
-    fn forceCompleteRound(&mut self) -> Result<(), GameError>;
+    fn force_complete_round(&mut self) -> Result<(), GameError>;
+
+    /// closes out the game once there is no round left pending completion
+    /// emits a relevant event
+    #[ink(message, payable)]
+    fn end_game(&mut self) -> Result<(), GameError>;
 }